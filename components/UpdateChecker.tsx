@@ -20,6 +20,23 @@ type UpdateStatus =
   | { state: "error"; message: string }
   | { state: "upToDate" };
 
+/** Erreur typée renvoyée par les commandes Rust (`UpdaterError`). */
+type UpdaterError = {
+  kind:
+    | "offline"
+    | "network"
+    | "auth"
+    | "rate_limited"
+    | "parse"
+    | "not_found"
+    | "config"
+    | "install"
+    | "cancelled";
+  message: string;
+  /** Présent pour `rate_limited` : réinitialisation du quota (secondes epoch) */
+  reset?: number;
+};
+
 /* ─── Helpers ─── */
 
 /** Compare deux versions semver. Retourne true si remote > local. */
@@ -61,7 +78,8 @@ export function UpdateChecker() {
   const downloadProgress = useRef({ downloaded: 0, contentLength: 0 });
 
   /* ─── Vérification via commande Tauri (get_app_versions = API GitHub côté Rust) ─── */
-  const checkForUpdate = useCallback(async () => {
+  // force : ignore le cache disque côté Rust (vérification demandée depuis le menu)
+  const checkForUpdate = useCallback(async (force = false) => {
     if (typeof window === "undefined") return;
 
     try {
@@ -71,11 +89,12 @@ export function UpdateChecker() {
       setStatus({ state: "checking" });
 
       const { invoke } = await import("@tauri-apps/api/core");
-      const v = (await invoke("get_app_versions", {})) as {
+      const v = (await invoke("get_app_versions", { force })) as {
         current: string;
         latest: string | null;
         latest_notes: string | null;
-        api_error?: string | null;
+        api_error?: UpdaterError | null;
+        from_cache?: boolean;
       };
 
       setCurrentVersion(v.current);
@@ -83,7 +102,7 @@ export function UpdateChecker() {
       const githubVersion = v.latest ?? "(indisponible)";
       devLog("Projet Paris", "Version", v.current, "| GitHub:", githubVersion);
       if (v.api_error) {
-        devWarn("Projet Paris", "API GitHub", v.api_error);
+        devWarn("Projet Paris", "API GitHub", v.api_error.kind, v.api_error.message);
       }
 
       if (!v.latest) {
@@ -121,13 +140,13 @@ export function UpdateChecker() {
         available: boolean;
         version: string | null;
         body: string | null;
-        error: string | null;
+        error: UpdaterError | null;
       };
 
       if (!updateInfo.available || updateInfo.error) {
         setStatus({
           state: "error",
-          message: updateInfo.error || "Aucune mise à jour disponible",
+          message: updateInfo.error?.message || "Aucune mise à jour disponible",
         });
         return;
       }
@@ -183,7 +202,7 @@ export function UpdateChecker() {
 
   /* ─── Auto-check au démarrage ─── */
   useEffect(() => {
-    const timer = setTimeout(checkForUpdate, 3000);
+    const timer = setTimeout(() => checkForUpdate(), 3000);
     return () => clearTimeout(timer);
   }, [checkForUpdate]);
 
@@ -191,7 +210,7 @@ export function UpdateChecker() {
   useEffect(() => {
     const handler = () => {
       setDismissed(false);
-      checkForUpdate();
+      checkForUpdate(true);
     };
     window.addEventListener("projet-paris:check-update", handler);
     return () => window.removeEventListener("projet-paris:check-update", handler);
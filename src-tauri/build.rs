@@ -1,3 +1,62 @@
 fn main() {
+  // Empreinte du build courant : fournie par la CI (BUILD_HASH) ou, à défaut, le commit git.
+  // Permet de proposer une mise à jour « même version » quand le contenu a changé.
+  println!("cargo:rerun-if-env-changed=BUILD_HASH");
+  let build_hash = std::env::var("BUILD_HASH")
+    .ok()
+    .filter(|h| !h.trim().is_empty())
+    .or_else(git_head)
+    .unwrap_or_default();
+  println!("cargo:rustc-env=BUILD_HASH={}", build_hash.trim());
+  println!(
+    "cargo:rustc-env=BUILD_GIT_SHA={}",
+    git_head().unwrap_or_default().trim()
+  );
+
+  // Date du build (SOURCE_DATE_EPOCH pour les builds reproductibles) et triple cible
+  println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+  let timestamp = std::env::var("SOURCE_DATE_EPOCH")
+    .ok()
+    .and_then(|t| t.trim().parse::<u64>().ok())
+    .unwrap_or_else(|| {
+      std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+    });
+  println!("cargo:rustc-env=BUILD_TIMESTAMP={}", timestamp);
+  println!(
+    "cargo:rustc-env=BUILD_TARGET={}",
+    std::env::var("TARGET").unwrap_or_default()
+  );
+
+  // Version résolue du plugin updater (Cargo.lock), pour corréler les rapports de bug
+  println!("cargo:rerun-if-changed=Cargo.lock");
+  let plugin_version = locked_version("tauri-plugin-updater").unwrap_or_else(|| "unknown".into());
+  println!("cargo:rustc-env=UPDATER_PLUGIN_VERSION={}", plugin_version);
+
+  // Dépôt des releases par défaut pour les forks, lu via option_env!("GITHUB_UPDATE_REPO")
+  println!("cargo:rerun-if-env-changed=GITHUB_UPDATE_REPO");
+
   tauri_build::build()
 }
+
+fn git_head() -> Option<String> {
+  let output = std::process::Command::new("git")
+    .args(["rev-parse", "HEAD"])
+    .output()
+    .ok()?;
+  if !output.status.success() {
+    return None;
+  }
+  String::from_utf8(output.stdout).ok()
+}
+
+fn locked_version(package: &str) -> Option<String> {
+  let lock = std::fs::read_to_string("Cargo.lock").ok()?;
+  let name_line = format!("name = \"{}\"", package);
+  let mut lines = lock.lines();
+  lines.find(|line| line.trim() == name_line)?;
+  let version = lines.next()?.trim().strip_prefix("version = ")?;
+  Some(version.trim_matches('"').to_string())
+}
@@ -0,0 +1,1081 @@
+// Les messages propres à une plateforme ou au desktop ne sont pas construits sur mobile
+#![cfg_attr(mobile, allow(dead_code))]
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lang {
+    En,
+    Fr,
+}
+
+/// Langue des messages : UPDATER_LANG, puis LANG (`fr_FR.UTF-8`...) ; anglais par défaut.
+fn message_lang() -> Lang {
+    let value = std::env::var("UPDATER_LANG")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .or_else(|| std::env::var("LANG").ok())
+        .unwrap_or_default();
+    if value.trim().to_ascii_lowercase().starts_with("fr") {
+        Lang::Fr
+    } else {
+        Lang::En
+    }
+}
+
+/// Catalogue des messages renvoyés au frontend (`error`, `api_error`) et des journaux
+/// de diagnostic.
+pub(crate) enum Msg {
+    /// Porte le token déjà masqué par `redact`
+    TokenFound(String),
+    TokenMissing,
+    HttpClientError(String),
+    Offline,
+    RateLimited { wait_min: i64, hour: i64, minute: i64 },
+    RateLimitedLater,
+    NoBetaRelease,
+    NotModifiedWithoutCache,
+    UnknownBackend(String),
+    ReleaseWithoutTag,
+    NoRelease,
+    StaticUrlRequired,
+    InvalidReleasesJson(String),
+    NoReleaseInFile,
+    InvalidVersion { version: String, error: String },
+    NoReleaseForTag(String),
+    ManifestMissing { asset: &'static str, tag: String },
+    NoPlatformBuild,
+    MigrationInProgress,
+    InstallFailed(String),
+    InvalidApiUrl(String),
+    SandboxFailed(String),
+    DowngradeBlocked,
+    ChecksumMismatch,
+    InsufficientDiskSpace,
+    UpdateInProgress,
+    MeteredConnection,
+    Cancelled,
+    TokenRequired,
+    ProfileAndTokenRequired,
+    UnknownTokenProfile(String),
+    ConfigDirMissing,
+    DownloadFailed(String),
+    SignatureNotTrusted,
+    ToolUnavailable(&'static str, String),
+    #[cfg(target_os = "windows")]
+    AuthenticodeUnreadable,
+    #[cfg(target_os = "windows")]
+    AuthenticodeInvalid(String),
+    #[cfg(target_os = "windows")]
+    SignerWithoutCn,
+    #[cfg(target_os = "macos")]
+    UpdateArchiveUnreadable,
+    #[cfg(target_os = "macos")]
+    NoAppBundle,
+    #[cfg(target_os = "macos")]
+    BundleWithoutTeamId,
+    UnexpectedPublisher { observed: String, expected: String },
+    SpkiPinningFailed(String),
+    InvalidPolicy(String),
+    InvalidAllowedVersions,
+    #[cfg(any(target_os = "windows", target_os = "linux"))]
+    CurrentExeUnknown,
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    ArchiveExtractionFailed(String),
+    #[cfg(target_os = "linux")]
+    DebExtractionFailed(String),
+    ExecutableMissing,
+    #[cfg(target_os = "linux")]
+    NoAppImage,
+    #[cfg(target_os = "windows")]
+    MsiOnlySandbox,
+    #[cfg(target_os = "windows")]
+    AdminInstallFailed(String),
+    LaunchFailed(String),
+    LaunchTimeout,
+    LaunchExitFailed(String),
+    NoVersionPrinted,
+    SandboxVersionMismatch { reported: String, expected: String },
+    #[cfg(target_os = "windows")]
+    NoRetainedInstaller,
+    InvalidUrl(String),
+    WebhookNotHttp,
+    #[cfg(target_os = "macos")]
+    NotInAppBundle,
+    #[cfg(target_os = "macos")]
+    MacAppStoreInstall,
+    #[cfg(target_os = "macos")]
+    DirNeedsAdmin(String),
+    #[cfg(target_os = "windows")]
+    MsixInstall,
+    #[cfg(target_os = "windows")]
+    PerMachineInstall,
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    PackageManagerInstall,
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    ReadOnlyDir(String),
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    SystemPackageInstall,
+    ReadyToInstall,
+    UpdateNotificationTitle,
+    UpdateNotificationBody(String),
+    TestNotificationTitle,
+    TestNotificationBody,
+    // Journaux de diagnostic
+    RepoIgnored { repo: String },
+    TokenFormatSuspicious,
+    TokenRejected { redacted: String },
+    TokenValid { login: Option<String> },
+    NoKeyringToken { error: String },
+    StoredTokenFormat { redacted: String },
+    TokenStored,
+    TokenRemoved,
+    TokenProfileUnreadable { name: String, error: String },
+    TokenProfileActive { name: String },
+    InstallIdNotSaved { error: String },
+    SystemProxyIgnored { url: String, error: String },
+    InvalidDuration { var: String, value: String, default_ms: u64 },
+    MinimumNotComparable { minimum: String, current: String },
+    TrustedKeyMatched { version: String, index: usize },
+    TrustedKeyNoMatch { version: String },
+    ConnectionFailed { error: String },
+    NoPlatformAsset { tag: String, platform: String, names: String },
+    VersionNotComparable { version: String, error: String },
+    ChannelSet { channel: String },
+    ChannelNotSaved { error: String },
+    ReleaseCacheNotSaved { error: String },
+    InvalidMaxRetries { value: String },
+    ApiRetry { reason: String, attempt: u32, attempts: u32, delay_ms: u128 },
+    TokenRefusedRetrying,
+    LastCheckNotSaved { error: String },
+    StaticReleaseIgnored { tag: String, error: String },
+    NoReleaseForInstalled { version: String },
+    MirrorIncomplete { url: String, missing: String },
+    LatestNotesUnavailable { error: String },
+    InstalledNotesUnavailable { error: String },
+    PublisherObserved { observed: String, expected: String },
+    PublisherCheckUnavailable,
+    DownloadProgress { version: String, percent: u64, downloaded: u64, total: u64 },
+    DownloadBytes { version: String, downloaded: u64 },
+    SpkiHostUnpinned { host: String },
+    SpkiMismatch { host: String, hash: String },
+    SpkiUnreadable { host: String },
+    AllowedVersionIgnored { version: String, error: String },
+    PolicyNotApplied { error: String },
+    PolicyApplied { changed: String },
+    ReleaseForVersionNotFound { version: String, error: String },
+    VersionNotAllowed { version: String },
+    VersionNotAllowedTarget { version: String, target: String },
+    AllowedReleaseNotFound { version: String, error: String },
+    NoReleaseForAllowed { version: String },
+    AllowedUpdaterUnavailable { version: String, error: String },
+    DownloadUrlUnavailable { error: String },
+    DowngradeAllowed { current: String, candidate: String },
+    DowngradeRefused { current: String, candidate: String },
+    SecurityUpdateAvailable { version: String },
+    VersionSkippedByUser { version: String },
+    ChecksumsUnreadable { version: String, error: String },
+    NoChecksumPublished,
+    ChecksumUnexpected { actual: String, expected: String },
+    FreeSpaceUnknown { dir: String, error: String },
+    DiskSpaceLow { dir: String, available: u64, required: u64 },
+    ArtifactDates { file: String, uploaded: Option<String>, published: Option<String> },
+    ArtifactStale { file: String, secs: u64, version: String },
+    RelaunchWithArgs { args: Vec<String> },
+    InstallAlreadyRunning,
+    MeteredDeferred { version: String },
+    ConnectionCostUnknown,
+    SandboxInstallFailed { version: String, error: String },
+    InstallMarkerNotSaved { error: String },
+    #[cfg(target_os = "windows")]
+    InstallerNotRetained { error: String },
+    PendingRestartNotSaved { error: String },
+    CancelRequested,
+    PollerStopped,
+    NotificationNotAllowed,
+    NotificationFailed { error: String },
+    InvalidPollMinutes { value: String },
+    PollerDisabled,
+    PollFailed { failures: u32, error: String },
+    VersionSkipped { version: Option<String> },
+    DownloadCancelled { version: String },
+    SandboxNotRemoved { dir: String, error: String },
+    SandboxInstalled { version: String, exe: String },
+    SandboxVerified { version: String },
+    #[cfg(target_os = "windows")]
+    ElevatedRelaunch { version: String },
+    #[cfg(target_os = "windows")]
+    ElevationRefused { version: String, reason: String },
+    StartupInfo {
+        version: String,
+        plugin: String,
+        commit: Option<String>,
+        target: String,
+        age_secs: u64,
+    },
+    InstallInterrupted { version: String, age: u64 },
+    RecoveryNotSaved { error: String },
+    RestartIneffective { installed: String, running: String },
+    EventNotEmitted { event: String, error: String },
+    WebhookClientUnavailable { error: String },
+    WebhookStatus { status: String, attempt: u64, attempts: u64 },
+    WebhookFailed { error: String, attempt: u64, attempts: u64 },
+    #[cfg(target_os = "macos")]
+    MacAutoUpdateHint { hint: String },
+    #[cfg(target_os = "macos")]
+    MacSandboxed,
+    #[cfg(target_os = "macos")]
+    MacTranslocated,
+    #[cfg(target_os = "macos")]
+    MacUnsigned,
+    NotificationsDenied { permission: String },
+    TestNotificationFailed { error: String },
+    // Commandes DevTools et journaux
+    WindowNotFound(String),
+    UnknownLogLevel(String),
+    LogLevelSet(String),
+    LogFileUnreadable(String),
+}
+
+impl Msg {
+    fn text(&self, lang: Lang) -> String {
+        let (en, fr) = match self {
+            Msg::TokenFound(redacted) => (
+                format!("Update token present ({})", redacted),
+                format!("Token de mise à jour présent ({})", redacted),
+            ),
+            Msg::TokenMissing => (
+                "No update token".to_string(),
+                "Aucun token de mise à jour".to_string(),
+            ),
+            Msg::Offline => (
+                "You appear to be offline".to_string(),
+                "Vous semblez hors ligne".to_string(),
+            ),
+            Msg::HttpClientError(e) => (
+                format!("HTTP client unavailable: {}", e),
+                format!("Client HTTP indisponible: {}", e),
+            ),
+            Msg::RateLimited { wait_min, hour, minute } => (
+                format!(
+                    "GitHub API rate limit reached: retry in {} min ({:02}:{:02} UTC)",
+                    wait_min, hour, minute
+                ),
+                format!(
+                    "Limite de l'API GitHub atteinte : réessayez dans {} min \
+                     ({:02}:{:02} UTC)",
+                    wait_min, hour, minute
+                ),
+            ),
+            Msg::RateLimitedLater => (
+                "GitHub API rate limit reached: retry later".to_string(),
+                "Limite de l'API GitHub atteinte : réessayez plus tard".to_string(),
+            ),
+            Msg::NoBetaRelease => (
+                "No release published on the beta channel".to_string(),
+                "Aucune release publiée sur le canal beta".to_string(),
+            ),
+            Msg::NotModifiedWithoutCache => (
+                "304 response without a cached release".to_string(),
+                "Réponse 304 sans release en cache".to_string(),
+            ),
+            Msg::UnknownBackend(backend) => (
+                format!("Unknown UPDATER_BACKEND: {}", backend),
+                format!("UPDATER_BACKEND inconnu: {}", backend),
+            ),
+            Msg::ReleaseWithoutTag => {
+                ("Release without a tag".to_string(), "Release sans tag".to_string())
+            }
+            Msg::NoRelease => {
+                ("No release published".to_string(), "Aucune release publiée".to_string())
+            }
+            Msg::StaticUrlRequired => (
+                "UPDATER_STATIC_URL is required for the static backend".to_string(),
+                "UPDATER_STATIC_URL requis pour le backend statique".to_string(),
+            ),
+            Msg::InvalidReleasesJson(e) => {
+                (format!("releases.json: {}", e), format!("releases.json: {}", e))
+            }
+            Msg::NoReleaseInFile => (
+                "No release in releases.json".to_string(),
+                "Aucune release dans releases.json".to_string(),
+            ),
+            Msg::InvalidVersion { version, error } => (
+                format!("Invalid version \"{}\": {}", version, error),
+                format!("Version invalide « {} »: {}", version, error),
+            ),
+            Msg::NoReleaseForTag(tag) => (
+                format!("No release for tag {}", tag),
+                format!("Aucune release pour le tag {}", tag),
+            ),
+            Msg::ManifestMissing { asset, tag } => (
+                format!("{} missing from release {}", asset, tag),
+                format!("{} absent de la release {}", asset, tag),
+            ),
+            Msg::NoPlatformBuild => (
+                "No build available for your platform in this release".to_string(),
+                "Aucun build disponible pour votre plateforme dans cette release".to_string(),
+            ),
+            Msg::MigrationInProgress => (
+                "A database migration is in progress, retry once it has finished".to_string(),
+                "Une migration de base de données est en cours, réessayez une fois terminée"
+                    .to_string(),
+            ),
+            Msg::InstallFailed(e) => (
+                format!("Installation failed: {}", e),
+                format!("Installation échouée: {}", e),
+            ),
+            Msg::InvalidApiUrl(url) => (
+                format!("Invalid GitHub API URL: {}", url),
+                format!("URL de l'API GitHub invalide: {}", url),
+            ),
+            Msg::SandboxFailed(e) => (
+                format!("Sandbox install failed: {}", e),
+                format!("Installation en bac à sable échouée: {}", e),
+            ),
+            Msg::DowngradeBlocked => (
+                "Downgrade blocked".to_string(),
+                "Retour à une version antérieure bloqué".to_string(),
+            ),
+            Msg::ChecksumMismatch => (
+                "Checksum mismatch".to_string(),
+                "Somme de contrôle incorrecte".to_string(),
+            ),
+            Msg::InsufficientDiskSpace => (
+                "Insufficient disk space".to_string(),
+                "Espace disque insuffisant".to_string(),
+            ),
+            Msg::UpdateInProgress => (
+                "Update already in progress".to_string(),
+                "Mise à jour déjà en cours".to_string(),
+            ),
+            Msg::MeteredConnection => (
+                "Metered connection".to_string(),
+                "Connexion limitée".to_string(),
+            ),
+            Msg::Cancelled => (
+                "Cancelled".to_string(),
+                "Annulé".to_string(),
+            ),
+            Msg::TokenRequired => (
+                "The token is required".to_string(),
+                "Le token est requis".to_string(),
+            ),
+            Msg::ProfileAndTokenRequired => (
+                "The profile name and the token are required".to_string(),
+                "Le nom du profil et le token sont requis".to_string(),
+            ),
+            Msg::UnknownTokenProfile(name) => (
+                format!("Unknown token profile: {}", name),
+                format!("Profil de token inconnu: {}", name),
+            ),
+            Msg::ConfigDirMissing => (
+                "Configuration folder not found".to_string(),
+                "Dossier de configuration introuvable".to_string(),
+            ),
+            Msg::DownloadFailed(status) => (
+                format!("Download failed: {}", status),
+                format!("Téléchargement échoué: {}", status),
+            ),
+            Msg::SignatureNotTrusted => (
+                "Signature not recognised by the trusted keys".to_string(),
+                "Signature non reconnue par les clés de confiance".to_string(),
+            ),
+            Msg::ToolUnavailable(tool, error) => (
+                format!("{} unavailable: {}", tool, error),
+                format!("{} indisponible: {}", tool, error),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::AuthenticodeUnreadable => (
+                "Unreadable Authenticode signature".to_string(),
+                "Signature Authenticode illisible".to_string(),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::AuthenticodeInvalid(status) => (
+                format!("Invalid Authenticode signature ({})", status),
+                format!("Signature Authenticode invalide ({})", status),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::SignerWithoutCn => (
+                "Signing certificate without CN".to_string(),
+                "Certificat de signature sans CN".to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::UpdateArchiveUnreadable => (
+                "Unreadable update archive".to_string(),
+                "Archive de mise à jour illisible".to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::NoAppBundle => (
+                "No .app bundle in the update archive".to_string(),
+                "Aucun bundle .app dans l'archive de mise à jour".to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::BundleWithoutTeamId => (
+                "Unsigned update bundle (no Team ID)".to_string(),
+                "Bundle de mise à jour non signé (pas de Team ID)".to_string(),
+            ),
+            Msg::UnexpectedPublisher { observed, expected } => (
+                format!("Unexpected publisher: {} (expected: {})", observed, expected),
+                format!("Éditeur inattendu: {} (attendu: {})", observed, expected),
+            ),
+            Msg::SpkiPinningFailed(error) => (
+                format!("SPKI pinning unavailable: {}", error),
+                format!("Épinglage SPKI impossible: {}", error),
+            ),
+            Msg::InvalidPolicy(error) => (
+                format!("Invalid update policy: {}", error),
+                format!("Politique de mise à jour invalide: {}", error),
+            ),
+            Msg::InvalidAllowedVersions => (
+                "allowed_versions contains an invalid version".to_string(),
+                "allowed_versions contient une version invalide".to_string(),
+            ),
+            #[cfg(any(target_os = "windows", target_os = "linux"))]
+            Msg::CurrentExeUnknown => (
+                "Unknown name for the running executable".to_string(),
+                "Nom de l'exécutable en cours inconnu".to_string(),
+            ),
+            #[cfg(any(target_os = "linux", target_os = "macos"))]
+            Msg::ArchiveExtractionFailed(status) => (
+                format!("Archive extraction failed ({})", status),
+                format!("Extraction de l'archive échouée ({})", status),
+            ),
+            #[cfg(target_os = "linux")]
+            Msg::DebExtractionFailed(status) => (
+                format!("Deb package extraction failed ({})", status),
+                format!("Extraction du paquet deb échouée ({})", status),
+            ),
+            Msg::ExecutableMissing => (
+                "No executable in the update artifact".to_string(),
+                "Exécutable absent de l'artefact de mise à jour".to_string(),
+            ),
+            #[cfg(target_os = "linux")]
+            Msg::NoAppImage => (
+                "No AppImage in the archive".to_string(),
+                "Aucune AppImage dans l'archive".to_string(),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::MsiOnlySandbox => (
+                "Only an MSI installer can be installed in the sandbox".to_string(),
+                "Seul un installeur MSI peut être installé en bac à sable".to_string(),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::AdminInstallFailed(status) => (
+                format!("Administrative install failed ({})", status),
+                format!("Installation administrative échouée ({})", status),
+            ),
+            Msg::LaunchFailed(error) => (
+                format!("Could not launch: {}", error),
+                format!("Lancement impossible: {}", error),
+            ),
+            Msg::LaunchTimeout => (
+                "Launch timed out".to_string(),
+                "Délai de lancement dépassé".to_string(),
+            ),
+            Msg::LaunchExitFailed(status) => (
+                format!("Launch failed ({})", status),
+                format!("Lancement en échec ({})", status),
+            ),
+            Msg::NoVersionPrinted => (
+                "No version printed at launch".to_string(),
+                "Aucune version affichée au lancement".to_string(),
+            ),
+            Msg::SandboxVersionMismatch { reported, expected } => (
+                format!("The installed version reports {} instead of {}", reported, expected),
+                format!("La version installée affiche {} au lieu de {}", reported, expected),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::NoRetainedInstaller => (
+                "No downloaded installer to relaunch".to_string(),
+                "Aucun installeur téléchargé à relancer".to_string(),
+            ),
+            Msg::InvalidUrl(error) => (
+                format!("Invalid URL: {}", error),
+                format!("URL invalide: {}", error),
+            ),
+            Msg::WebhookNotHttp => (
+                "The webhook must be an http(s) URL".to_string(),
+                "Le webhook doit être une URL http(s)".to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::NotInAppBundle => (
+                "The application is not running from a .app bundle".to_string(),
+                "L'application ne s'exécute pas depuis un bundle .app".to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::MacAppStoreInstall => (
+                "Installed from the Mac App Store: updates go through the App Store"
+                    .to_string(),
+                "Installée depuis le Mac App Store : les mises à jour passent par l'App Store"
+                    .to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::DirNeedsAdmin(dir) => (
+                format!("{} cannot be modified without an administrator password", dir),
+                format!("{} n'est pas modifiable sans mot de passe administrateur", dir),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::MsixInstall => (
+                "Installation managed by the Microsoft Store (MSIX)".to_string(),
+                "Installation gérée par le Microsoft Store (MSIX)".to_string(),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::PerMachineInstall => (
+                "Installation for all users: the installer will request UAC elevation"
+                    .to_string(),
+                "Installation pour tous les utilisateurs : l'installeur demandera \
+                 l'élévation UAC"
+                    .to_string(),
+            ),
+            #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+            Msg::PackageManagerInstall => (
+                "Installed through Flatpak, Snap or Nix: update it with that package manager"
+                    .to_string(),
+                "Installée via Flatpak, Snap ou Nix : mettez-la à jour par ce gestionnaire"
+                    .to_string(),
+            ),
+            #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+            Msg::ReadOnlyDir(dir) => (
+                format!("{} is read-only", dir),
+                format!("{} est en lecture seule", dir),
+            ),
+            #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+            Msg::SystemPackageInstall => (
+                "System package: the installation will ask for the administrator password"
+                    .to_string(),
+                "Paquet système : l'installation demandera le mot de passe administrateur"
+                    .to_string(),
+            ),
+            Msg::ReadyToInstall => (
+                "The update can be installed".to_string(),
+                "La mise à jour peut être installée".to_string(),
+            ),
+            Msg::UpdateNotificationTitle => (
+                "Update available".to_string(),
+                "Mise à jour disponible".to_string(),
+            ),
+            Msg::UpdateNotificationBody(version) => (
+                format!("Version {} available", version),
+                format!("Version {} disponible", version),
+            ),
+            Msg::TestNotificationTitle => (
+                "Update notifications".to_string(),
+                "Notifications de mise à jour".to_string(),
+            ),
+            Msg::TestNotificationBody => (
+                "Update notifications are working.".to_string(),
+                "Les notifications de mise à jour fonctionnent.".to_string(),
+            ),
+            Msg::RepoIgnored { repo } => (
+                format!("GITHUB_UPDATE_REPO ignored (expected owner/repo): {}", repo),
+                format!("GITHUB_UPDATE_REPO ignoré (format attendu owner/repo): {}", repo),
+            ),
+            Msg::TokenFormatSuspicious => (
+                "The update token does not look like a GitHub token (truncated or badly \
+                 copied?)"
+                    .to_string(),
+                "Le token de mise à jour ne ressemble pas à un token GitHub (tronqué ou mal \
+                 copié ?)"
+                    .to_string(),
+            ),
+            Msg::TokenRejected { redacted } => (
+                format!("Update token rejected by GitHub ({})", redacted),
+                format!("Token de mise à jour refusé par GitHub ({})", redacted),
+            ),
+            Msg::TokenValid { login } => (
+                format!("Valid update token (account {:?})", login),
+                format!("Token de mise à jour valide (compte {:?})", login),
+            ),
+            Msg::NoKeyringToken { error } => (
+                format!("No update token in the keyring: {}", error),
+                format!("Aucun token de mise à jour dans le trousseau: {}", error),
+            ),
+            Msg::StoredTokenFormat { redacted } => (
+                format!("Stored token has an unexpected format ({})", redacted),
+                format!("Token enregistré au format inattendu ({})", redacted),
+            ),
+            Msg::TokenStored => (
+                "Update token stored in the keyring".to_string(),
+                "Token de mise à jour enregistré dans le trousseau".to_string(),
+            ),
+            Msg::TokenRemoved => (
+                "Update token removed from the keyring".to_string(),
+                "Token de mise à jour retiré du trousseau".to_string(),
+            ),
+            Msg::TokenProfileUnreadable { name, error } => (
+                format!("Unreadable token profile '{}': {}", name, error),
+                format!("Profil de token « {} » illisible: {}", name, error),
+            ),
+            Msg::TokenProfileActive { name } => (
+                format!("Active token profile: {}", name),
+                format!("Profil de token actif: {}", name),
+            ),
+            Msg::InstallIdNotSaved { error } => (
+                format!("Installation ID not saved: {}", error),
+                format!("Identifiant d'installation non persisté: {}", error),
+            ),
+            Msg::SystemProxyIgnored { url, error } => (
+                format!("System proxy ignored ({}): {}", url, error),
+                format!("Proxy système ignoré ({}): {}", url, error),
+            ),
+            Msg::InvalidDuration { var, value, default_ms } => (
+                format!("Invalid {} '{}', using {} ms", var, value, default_ms),
+                format!("{} invalide « {} », {} ms utilisés", var, value, default_ms),
+            ),
+            Msg::MinimumNotComparable { minimum, current } => (
+                format!("Minimum version '{}' cannot be compared with {}", minimum, current),
+                format!("Version minimale « {} » non comparable à {}", minimum, current),
+            ),
+            Msg::TrustedKeyMatched { version, index } => (
+                format!("Update {} verified with trusted key #{}", version, index),
+                format!(
+                    "Mise à jour {} vérifiée avec la clé de confiance n°{}",
+                    version, index
+                ),
+            ),
+            Msg::TrustedKeyNoMatch { version } => (
+                format!("Signature of update {} rejected: no trusted key matches", version),
+                format!(
+                    "Signature de la mise à jour {} rejetée : aucune clé de confiance ne \
+                     correspond",
+                    version
+                ),
+            ),
+            Msg::ConnectionFailed { error } => (
+                format!("Connection failed: {}", error),
+                format!("Connexion impossible: {}", error),
+            ),
+            Msg::NoPlatformAsset { tag, platform, names } => (
+                format!("No asset of {} for {}; available assets: {}", tag, platform, names),
+                format!(
+                    "Aucun asset de {} pour {} ; assets disponibles: {}",
+                    tag, platform, names
+                ),
+            ),
+            Msg::VersionNotComparable { version, error } => (
+                format!("Version '{}' cannot be compared: {}", version, error),
+                format!("Version « {} » non comparable: {}", version, error),
+            ),
+            Msg::ChannelSet { channel } => (
+                format!("Update channel: {}", channel),
+                format!("Canal de mise à jour: {}", channel),
+            ),
+            Msg::ChannelNotSaved { error } => (
+                format!("Update channel not saved: {}", error),
+                format!("Canal de mise à jour non enregistré: {}", error),
+            ),
+            Msg::ReleaseCacheNotSaved { error } => (
+                format!("Release cache not saved: {}", error),
+                format!("Cache de la release non enregistré: {}", error),
+            ),
+            Msg::InvalidMaxRetries { value } => (
+                format!("Invalid UPDATER_MAX_RETRIES '{}'", value),
+                format!("UPDATER_MAX_RETRIES invalide « {} »", value),
+            ),
+            Msg::ApiRetry { reason, attempt, attempts, delay_ms } => (
+                format!(
+                    "API call failed ({}), attempt {}/{} in {} ms",
+                    reason, attempt, attempts, delay_ms
+                ),
+                format!(
+                    "Appel API échoué ({}), tentative {}/{} dans {} ms",
+                    reason, attempt, attempts, delay_ms
+                ),
+            ),
+            Msg::TokenRefusedRetrying => (
+                "Update token refused (401): retrying without a token".to_string(),
+                "Token de mise à jour refusé (401) : nouvelle tentative sans token"
+                    .to_string(),
+            ),
+            Msg::LastCheckNotSaved { error } => (
+                format!("Last check date not saved: {}", error),
+                format!("Date de dernière vérification non enregistrée: {}", error),
+            ),
+            Msg::StaticReleaseIgnored { tag, error } => (
+                format!("releases.json: '{}' ignored: {}", tag, error),
+                format!("releases.json: « {} » ignorée: {}", tag, error),
+            ),
+            Msg::NoReleaseForInstalled { version } => (
+                format!("No release published for the installed version {}", version),
+                format!("Aucune release publiée pour la version installée {}", version),
+            ),
+            Msg::MirrorIncomplete { url, missing } => (
+                format!("Mirror {} incomplete, missing fields: {}", url, missing),
+                format!("Miroir {} incomplet, champs manquants: {}", url, missing),
+            ),
+            Msg::LatestNotesUnavailable { error } => (
+                format!("Latest release notes unavailable: {}", error),
+                format!("Notes de la dernière release indisponibles: {}", error),
+            ),
+            Msg::InstalledNotesUnavailable { error } => (
+                format!("Installed version notes unavailable: {}", error),
+                format!("Notes de la version installée indisponibles: {}", error),
+            ),
+            Msg::PublisherObserved { observed, expected } => (
+                format!("Installer publisher: {} (expected: {})", observed, expected),
+                format!("Éditeur de l'installeur: {} (attendu: {})", observed, expected),
+            ),
+            Msg::PublisherCheckUnavailable => (
+                "Publisher check not available on this platform".to_string(),
+                "Vérification de l'éditeur non disponible sur cette plateforme".to_string(),
+            ),
+            Msg::DownloadProgress { version, percent, downloaded, total } => (
+                format!("Download {}: {}% ({}/{} bytes)", version, percent, downloaded, total),
+                format!(
+                    "Téléchargement {}: {}% ({}/{} octets)",
+                    version, percent, downloaded, total
+                ),
+            ),
+            Msg::DownloadBytes { version, downloaded } => (
+                format!("Download {}: {} bytes", version, downloaded),
+                format!("Téléchargement {}: {} octets", version, downloaded),
+            ),
+            Msg::SpkiHostUnpinned { host } => (
+                format!("Connection to {} refused: no pinned SPKI hash", host),
+                format!("Connexion à {} refusée : aucune empreinte SPKI épinglée", host),
+            ),
+            Msg::SpkiMismatch { host, hash } => (
+                format!(
+                    "Certificate of {} refused: SPKI sha256/{} is not among the pinned hashes",
+                    host, hash
+                ),
+                format!(
+                    "Certificat de {} refusé : SPKI sha256/{} absente des empreintes \
+                     épinglées",
+                    host, hash
+                ),
+            ),
+            Msg::SpkiUnreadable { host } => (
+                format!("Certificate of {} refused: unreadable SPKI", host),
+                format!("Certificat de {} refusé : SPKI illisible", host),
+            ),
+            Msg::AllowedVersionIgnored { version, error } => (
+                format!("Allowed version '{}' ignored: {}", version, error),
+                format!("Version autorisée ignorée « {} »: {}", version, error),
+            ),
+            Msg::PolicyNotApplied { error } => (
+                format!("Remote policy not applied: {}", error),
+                format!("Politique distante non appliquée: {}", error),
+            ),
+            Msg::PolicyApplied { changed } => (
+                format!("Remote policy applied (changed: {})", changed),
+                format!("Politique distante appliquée (modifié: {})", changed),
+            ),
+            Msg::ReleaseForVersionNotFound { version, error } => (
+                format!("Release of version {} not found: {}", version, error),
+                format!("Release de la version {} introuvable: {}", version, error),
+            ),
+            Msg::VersionNotAllowed { version } => (
+                format!("Version {} not allowed, no newer approved version", version),
+                format!(
+                    "Version {} non autorisée, aucune version approuvée plus récente",
+                    version
+                ),
+            ),
+            Msg::VersionNotAllowedTarget { version, target } => (
+                format!("Version {} not allowed, approved target: {}", version, target),
+                format!("Version {} non autorisée, cible approuvée: {}", version, target),
+            ),
+            Msg::AllowedReleaseNotFound { version, error } => (
+                format!("Release of allowed version {} not found: {}", version, error),
+                format!("Release de la version autorisée {} introuvable: {}", version, error),
+            ),
+            Msg::NoReleaseForAllowed { version } => (
+                format!("No release published for allowed version {}", version),
+                format!("Aucune release publiée pour la version autorisée {}", version),
+            ),
+            Msg::AllowedUpdaterUnavailable { version, error } => (
+                format!("Updater for allowed version {} unavailable: {}", version, error),
+                format!(
+                    "Updater pour la version autorisée {} indisponible: {}",
+                    version, error
+                ),
+            ),
+            Msg::DownloadUrlUnavailable { error } => (
+                format!("Download URL unavailable: {}", error),
+                format!("URL de téléchargement indisponible: {}", error),
+            ),
+            Msg::DowngradeAllowed { current, candidate } => (
+                format!("Downgrade allowed: {} -> {}", current, candidate),
+                format!("Retour arrière autorisé: {} -> {}", current, candidate),
+            ),
+            Msg::DowngradeRefused { current, candidate } => (
+                format!("Downgrade blocked: installed {}, candidate {}", current, candidate),
+                format!(
+                    "Rétrogradation bloquée: installée {}, candidate {}",
+                    current, candidate
+                ),
+            ),
+            Msg::SecurityUpdateAvailable { version } => (
+                format!("Security update available: {}", version),
+                format!("Mise à jour de sécurité disponible: {}", version),
+            ),
+            Msg::VersionSkippedByUser { version } => (
+                format!("Version {} skipped at the user's request", version),
+                format!("Version {} ignorée à la demande de l'utilisateur", version),
+            ),
+            Msg::ChecksumsUnreadable { version, error } => (
+                format!("Checksums of release {} unreadable: {}", version, error),
+                format!("Empreintes de la release {} illisibles: {}", version, error),
+            ),
+            Msg::NoChecksumPublished => (
+                "No SHA-256 checksum published: artifact check skipped".to_string(),
+                "Aucune empreinte SHA-256 publiée : vérification de l'artefact ignorée"
+                    .to_string(),
+            ),
+            Msg::ChecksumUnexpected { actual, expected } => (
+                format!("Unexpected SHA-256 checksum: {} instead of {}", actual, expected),
+                format!("Empreinte SHA-256 inattendue: {} au lieu de {}", actual, expected),
+            ),
+            Msg::FreeSpaceUnknown { dir, error } => (
+                format!("Free space of {} unknown: {}", dir, error),
+                format!("Espace libre de {} inconnu: {}", dir, error),
+            ),
+            Msg::DiskSpaceLow { dir, available, required } => (
+                format!(
+                    "Insufficient disk space in {}: {} bytes free, {} required",
+                    dir, available, required
+                ),
+                format!(
+                    "Espace disque insuffisant dans {}: {} octets libres, {} requis",
+                    dir, available, required
+                ),
+            ),
+            Msg::ArtifactDates { file, uploaded, published } => (
+                format!(
+                    "Artifact {}: uploaded {:?}, release published {:?}",
+                    file, uploaded, published
+                ),
+                format!(
+                    "Artefact {}: déposé le {:?}, release publiée le {:?}",
+                    file, uploaded, published
+                ),
+            ),
+            Msg::ArtifactStale { file, secs, version } => (
+                format!("Artifact {} is {} s older than release {}", file, secs, version),
+                format!("Artefact {} antérieur de {} s à la release {}", file, secs, version),
+            ),
+            Msg::RelaunchWithArgs { args } => (
+                format!("Relaunching with extra arguments {:?}", args),
+                format!("Relancement avec les arguments supplémentaires {:?}", args),
+            ),
+            Msg::InstallAlreadyRunning => (
+                "Update installation already in progress, request ignored".to_string(),
+                "Installation de mise à jour déjà en cours, demande ignorée".to_string(),
+            ),
+            Msg::MeteredDeferred { version } => (
+                format!("Metered connection: download of {} deferred", version),
+                format!("Connexion facturée : téléchargement de {} différé", version),
+            ),
+            Msg::ConnectionCostUnknown => (
+                "Connection cost unknown, download allowed".to_string(),
+                "Coût de la connexion inconnu, téléchargement autorisé".to_string(),
+            ),
+            Msg::SandboxInstallFailed { version, error } => (
+                format!("Sandbox install of {} failed: {}", version, error),
+                format!("Installation en bac à sable de {} échouée: {}", version, error),
+            ),
+            Msg::InstallMarkerNotSaved { error } => (
+                format!("Could not save the install marker: {}", error),
+                format!("Impossible d'enregistrer le marqueur d'installation: {}", error),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::InstallerNotRetained { error } => (
+                format!("Installer not kept for a retry: {}", error),
+                format!("Installeur non conservé pour une nouvelle tentative: {}", error),
+            ),
+            Msg::PendingRestartNotSaved { error } => (
+                format!("Pending restart not saved: {}", error),
+                format!("Redémarrage en attente non enregistré: {}", error),
+            ),
+            Msg::CancelRequested => (
+                "Update download cancellation requested".to_string(),
+                "Annulation du téléchargement de la mise à jour demandée".to_string(),
+            ),
+            Msg::PollerStopped => (
+                "Stopping periodic update checks".to_string(),
+                "Arrêt de la vérification périodique des mises à jour".to_string(),
+            ),
+            Msg::NotificationNotAllowed => (
+                "Update notification not allowed, skipped".to_string(),
+                "Notification de mise à jour non autorisée, ignorée".to_string(),
+            ),
+            Msg::NotificationFailed { error } => (
+                format!("Update notification not shown: {}", error),
+                format!("Notification de mise à jour non affichée: {}", error),
+            ),
+            Msg::InvalidPollMinutes { value } => (
+                format!("Invalid UPDATER_POLL_MINUTES '{}'", value),
+                format!("UPDATER_POLL_MINUTES invalide « {} »", value),
+            ),
+            Msg::PollerDisabled => (
+                "Periodic update checks disabled".to_string(),
+                "Vérification périodique des mises à jour désactivée".to_string(),
+            ),
+            Msg::PollFailed { failures, error } => (
+                format!("Periodic check failed ({}): {}", failures, error),
+                format!("Vérification périodique échouée ({}): {}", failures, error),
+            ),
+            Msg::VersionSkipped { version } => (
+                format!("Version {:?} skipped by the user", version),
+                format!("Version {:?} ignorée par l'utilisateur", version),
+            ),
+            Msg::DownloadCancelled { version } => (
+                format!("Download of update {} cancelled", version),
+                format!("Téléchargement de la mise à jour {} annulé", version),
+            ),
+            Msg::SandboxNotRemoved { dir, error } => (
+                format!("Sandbox {} not removed: {}", dir, error),
+                format!("Bac à sable {} non supprimé: {}", dir, error),
+            ),
+            Msg::SandboxInstalled { version, exe } => (
+                format!("Version {} installed in the sandbox: {}", version, exe),
+                format!("Version {} installée en bac à sable: {}", version, exe),
+            ),
+            Msg::SandboxVerified { version } => (
+                format!("Sandbox install of {} verified", version),
+                format!("Installation en bac à sable de {} vérifiée", version),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::ElevatedRelaunch { version } => (
+                format!("Installer {} relaunched with elevation", version),
+                format!("Installeur {} relancé avec élévation", version),
+            ),
+            #[cfg(target_os = "windows")]
+            Msg::ElevationRefused { version, reason } => (
+                format!("Elevation refused for installer {}: {}", version, reason),
+                format!("Élévation refusée pour l'installeur {}: {}", version, reason),
+            ),
+            Msg::StartupInfo { version, plugin, commit, target, age_secs } => (
+                format!(
+                    "Version {} (updater plugin {}, commit {}, target {}, built {} s ago)",
+                    version,
+                    plugin,
+                    commit.as_deref().unwrap_or("unknown"),
+                    target,
+                    age_secs
+                ),
+                format!(
+                    "Version {} (plugin updater {}, commit {}, cible {}, compilée il y a \
+                     {} s)",
+                    version,
+                    plugin,
+                    commit.as_deref().unwrap_or("inconnu"),
+                    target,
+                    age_secs
+                ),
+            ),
+            Msg::InstallInterrupted { version, age } => (
+                format!(
+                    "Install of version {} interrupted {} s ago, marker cleared",
+                    version, age
+                ),
+                format!(
+                    "Installation de la version {} interrompue il y a {} s, marqueur nettoyé",
+                    version, age
+                ),
+            ),
+            Msg::RecoveryNotSaved { error } => (
+                format!("Recovery from an interrupted install not saved: {}", error),
+                format!("Reprise après installation interrompue non enregistrée: {}", error),
+            ),
+            Msg::RestartIneffective { installed, running } => (
+                format!(
+                    "Version {} installed but version {} is still running",
+                    installed, running
+                ),
+                format!(
+                    "Version {} installée mais la version {} est toujours lancée",
+                    installed, running
+                ),
+            ),
+            Msg::EventNotEmitted { event, error } => (
+                format!("Event {} not emitted: {}", event, error),
+                format!("Événement {} non émis: {}", event, error),
+            ),
+            Msg::WebhookClientUnavailable { error } => (
+                format!("Monitoring webhook: HTTP client unavailable: {}", error),
+                format!("Webhook de supervision: client HTTP indisponible: {}", error),
+            ),
+            Msg::WebhookStatus { status, attempt, attempts } => (
+                format!(
+                    "Monitoring webhook: response {} (attempt {}/{})",
+                    status, attempt, attempts
+                ),
+                format!(
+                    "Webhook de supervision: réponse {} (tentative {}/{})",
+                    status, attempt, attempts
+                ),
+            ),
+            Msg::WebhookFailed { error, attempt, attempts } => (
+                format!("Monitoring webhook: {} (attempt {}/{})", error, attempt, attempts),
+                format!(
+                    "Webhook de supervision: {} (tentative {}/{})",
+                    error, attempt, attempts
+                ),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::MacAutoUpdateHint { hint } => (
+                format!("macOS auto-update: {}", hint),
+                format!("Auto-update macOS: {}", hint),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::MacSandboxed => (
+                "App Sandbox is enabled: auto-update is impossible, distribute without \
+                 the sandbox or through the App Store."
+                    .to_string(),
+                "L'App Sandbox est active : l'auto-update est impossible, distribuez sans \
+                 sandbox ou via l'App Store."
+                    .to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::MacTranslocated => (
+                "Move the application to /Applications and relaunch it to allow updates."
+                    .to_string(),
+                "Déplacez l'application dans /Applications puis relancez-la pour permettre \
+                 la mise à jour."
+                    .to_string(),
+            ),
+            #[cfg(target_os = "macos")]
+            Msg::MacUnsigned => (
+                "The application is not signed: Gatekeeper may block the installed version."
+                    .to_string(),
+                "L'application n'est pas signée : Gatekeeper peut bloquer la version \
+                 installée."
+                    .to_string(),
+            ),
+            Msg::NotificationsDenied { permission } => (
+                format!("Notifications not allowed ({})", permission),
+                format!("Notifications non autorisées ({})", permission),
+            ),
+            Msg::TestNotificationFailed { error } => (
+                format!("Test notification not shown: {}", error),
+                format!("Notification de test non affichée: {}", error),
+            ),
+            Msg::WindowNotFound(label) => (
+                format!("Window not found: {}", label),
+                format!("Fenêtre introuvable: {}", label),
+            ),
+            Msg::UnknownLogLevel(level) => (
+                format!("Unknown log level: {}", level),
+                format!("Niveau de log inconnu: {}", level),
+            ),
+            Msg::LogLevelSet(level) => (
+                format!("Log level: {}", level),
+                format!("Niveau de log: {}", level),
+            ),
+            Msg::LogFileUnreadable(error) => (
+                format!("Could not read the log file: {}", error),
+                format!("Lecture du fichier de log impossible: {}", error),
+            ),
+        };
+        match lang {
+            Lang::En => en,
+            Lang::Fr => fr,
+        }
+    }
+}
+
+/// Message du catalogue dans la langue configurée.
+pub(crate) fn tr(msg: Msg) -> String {
+    msg.text(message_lang())
+}
@@ -0,0 +1,21 @@
+//! Normalisation des tags de release avec les préfixes par défaut.
+#![cfg(desktop)]
+
+use app_lib::normalize_version_tag;
+
+#[test]
+fn normalize_version_tag_strips_known_prefixes_only() {
+    let cases = [
+        ("app-v1.2.3", "1.2.3"),
+        ("app-1.2.3", "1.2.3"),
+        ("v1.2.3", "1.2.3"),
+        ("1.2.3", "1.2.3"),
+        ("release-1.2.3", "1.2.3"),
+        ("v1.2.3-beta.1", "1.2.3-beta.1"),
+        ("", ""),
+        ("version-info", "version-info"),
+    ];
+    for (tag, expected) in cases {
+        assert_eq!(normalize_version_tag(tag), expected, "tag {:?}", tag);
+    }
+}